@@ -2,15 +2,38 @@ use anyhow::{Context, Result};
 use clap::Parser;
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
-    event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
+    event::{
+        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers,
+        MouseButton, MouseEvent, MouseEventKind,
+    },
     execute,
     style::{Color, Print, SetForegroundColor},
     terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
 use ignore::WalkBuilder;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
 use std::io::{self, Read, Write};
+use std::path::PathBuf;
 use std::process;
 use std::time::{Duration, Instant};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Standard RSVP Optimal Recognition Point: the grapheme within a word that
+/// should stay fixed under the reader's gaze as words are swapped in and out.
+fn orp_pivot_index(grapheme_count: usize) -> usize {
+    match grapheme_count {
+        0 | 1 => 0,
+        2..=5 => 1,
+        6..=9 => 2,
+        10..=13 => 3,
+        _ => 4,
+    }
+}
 
 #[derive(Parser)]
 #[command(version, about, long_about = None)]
@@ -23,13 +46,232 @@ struct Args {
 
     #[arg(short, long, name = "FILE")]
     file: Option<String>,
+
+    /// Color palette to use; "auto" detects the terminal's background color
+    #[arg(long, value_enum, default_value = "auto")]
+    theme: ThemeMode,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+enum ThemeMode {
+    Light,
+    Dark,
+    Auto,
+}
+
+/// The palette every render surface draws from, so the UI stays legible on
+/// both light and dark terminal backgrounds.
+struct Theme {
+    pivot: Color,
+    text: Color,
+    heading: Color,
+    controls_key: Color,
+    controls_label: Color,
+    status: Color,
+    picker_title: Color,
+    picker_prompt: Color,
+    picker_text: Color,
+    picker_selected: Color,
+    picker_match: Color,
+}
+
+impl Theme {
+    fn dark() -> Self {
+        Self {
+            pivot: Color::Red,
+            text: Color::White,
+            heading: Color::DarkGrey,
+            controls_key: Color::Cyan,
+            controls_label: Color::DarkGrey,
+            status: Color::Yellow,
+            picker_title: Color::Yellow,
+            picker_prompt: Color::Cyan,
+            picker_text: Color::White,
+            picker_selected: Color::Red,
+            picker_match: Color::Cyan,
+        }
+    }
+
+    fn light() -> Self {
+        Self {
+            pivot: Color::DarkRed,
+            text: Color::Black,
+            heading: Color::Grey,
+            controls_key: Color::DarkBlue,
+            controls_label: Color::Grey,
+            status: Color::DarkYellow,
+            picker_title: Color::DarkYellow,
+            picker_prompt: Color::DarkBlue,
+            picker_text: Color::Black,
+            picker_selected: Color::DarkRed,
+            picker_match: Color::DarkBlue,
+        }
+    }
+}
+
+/// Queries the terminal's background color via an OSC 11 escape sequence
+/// and returns its perceptual luminance (0.0 = black, 1.0 = white).
+fn query_background_luminance() -> Option<f64> {
+    print!("\x1b]11;?\x07");
+    io::stdout().flush().ok()?;
+
+    // Read the OSC 11 reply through crossterm's own event reader instead of a
+    // detached thread blocking on stdin: a thread left reading after the
+    // 200ms deadline would keep contending with the main event loop in
+    // `run()` and could swallow the user's first keypress. Terminals that
+    // don't understand OSC 11 send nothing, so there's no reliable
+    // end-of-reply marker to watch for here (crossterm's unix parser remaps
+    // control bytes like the BEL terminator to CONTROL-modified keys, and
+    // folds a leading ESC into the next key rather than emitting it on its
+    // own) — this loop just drains events until the deadline passes.
+    let deadline = Instant::now() + Duration::from_millis(200);
+    let mut response = String::new();
+    while Instant::now() < deadline {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if !event::poll(remaining).unwrap_or(false) {
+            break;
+        }
+        if let Event::Key(KeyEvent {
+            code: KeyCode::Char(c),
+            ..
+        }) = event::read().ok()?
+        {
+            response.push(c);
+        }
+    }
+
+    let rgb_start = response.find("rgb:")? + 4;
+    let channels: Vec<&str> = response[rgb_start..].splitn(3, '/').collect();
+    let parse_channel = |s: &str| -> Option<f64> {
+        u32::from_str_radix(s.get(..2)?, 16)
+            .ok()
+            .map(|v| v as f64 / 255.0)
+    };
+
+    let r = parse_channel(channels.first()?)?;
+    let g = parse_channel(channels.get(1)?)?;
+    let b = parse_channel(
+        channels
+            .get(2)?
+            .trim_end_matches(|c: char| c == '\u{7}' || c == '\u{1b}' || c == '\\'),
+    )?;
+
+    Some(0.2126 * r + 0.7152 * g + 0.0722 * b)
+}
+
+fn detect_theme(mode: ThemeMode) -> Theme {
+    match mode {
+        ThemeMode::Light => Theme::light(),
+        ThemeMode::Dark => Theme::dark(),
+        ThemeMode::Auto => match query_background_luminance() {
+            Some(luminance) if luminance > 0.5 => Theme::light(),
+            _ => Theme::dark(),
+        },
+    }
+}
+
+/// A candidate file in the picker along with the char indices (into the
+/// full path) of the characters that matched the current fuzzy filter (empty when unfiltered).
+struct FileMatch {
+    path: String,
+    indices: Vec<usize>,
+}
+
+/// What the reader's keyboard input currently drives: the word display,
+/// entering a new bookmark's name, or browsing the bookmark list.
+#[derive(Clone)]
+enum BookmarkMode {
+    None,
+    Naming(String),
+    Overlay { selected: usize },
+}
+
+/// Reading progress for a single file, persisted under the XDG data dir so
+/// the reader can offer to resume where a file was left off.
+struct ProgressState {
+    word_index: usize,
+    wpm: u32,
+    bookmarks: Vec<(String, usize)>,
+}
+
+fn progress_dir() -> Result<PathBuf> {
+    let mut dir = dirs::data_dir().context("Could not determine the user data directory")?;
+    dir.push("speed_reader");
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/// Identifies a file by path and size so progress is forgotten if the
+/// underlying content has clearly changed since it was last saved.
+fn hash_file_key(path: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    path.hash(&mut hasher);
+    if let Ok(metadata) = std::fs::metadata(path) {
+        metadata.len().hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
+
+fn progress_file_path(file_key: &str) -> Result<PathBuf> {
+    let mut path = progress_dir()?;
+    path.push(format!("{}.progress", file_key));
+    Ok(path)
+}
+
+fn load_progress(file_key: &str) -> Option<ProgressState> {
+    let path = progress_file_path(file_key).ok()?;
+    let contents = std::fs::read_to_string(path).ok()?;
+
+    let mut word_index = 0;
+    let mut wpm = 0;
+    let mut bookmarks = Vec::new();
+
+    for line in contents.lines() {
+        if let Some(value) = line.strip_prefix("word_index=") {
+            word_index = value.parse().unwrap_or(0);
+        } else if let Some(value) = line.strip_prefix("wpm=") {
+            wpm = value.parse().unwrap_or(0);
+        } else if let Some(rest) = line.strip_prefix("bookmark:") {
+            if let Some((name, index)) = rest.rsplit_once('=') {
+                if let Ok(index) = index.parse() {
+                    bookmarks.push((name.to_string(), index));
+                }
+            }
+        }
+    }
+
+    Some(ProgressState {
+        word_index,
+        wpm,
+        bookmarks,
+    })
+}
+
+fn save_progress(file_key: &str, state: &ProgressState) -> Result<()> {
+    let path = progress_file_path(file_key)?;
+
+    let mut contents = format!("word_index={}\nwpm={}\n", state.word_index, state.wpm);
+    for (name, word_index) in &state.bookmarks {
+        contents.push_str(&format!("bookmark:{}={}\n", name, word_index));
+    }
+
+    std::fs::write(path, contents).context("Failed to save reading progress")?;
+    Ok(())
 }
 
 struct SpeedReader {
     words: Vec<String>,
+    headings: HashMap<usize, String>,
+    filetype: FileType,
+    theme: Theme,
     current_word_index: usize,
     wpm: u32,
     is_paused: bool,
+    dynamic_pacing: bool,
+    file_key: Option<String>,
+    pending_resume: Option<ProgressState>,
+    bookmarks: Vec<(String, usize)>,
+    bookmark_mode: BookmarkMode,
 }
 
 pub struct FormatDuration(Duration);
@@ -44,12 +286,99 @@ impl std::fmt::Display for FormatDuration {
 }
 
 impl SpeedReader {
-    fn new(words: Vec<String>, wpm: u32) -> Self {
+    fn new(document: ParsedDocument, wpm: u32, filetype: FileType, theme: Theme) -> Self {
         Self {
-            words,
+            words: document.words,
+            headings: document.headings,
+            filetype,
+            theme,
             current_word_index: 0,
             wpm,
             is_paused: true,
+            dynamic_pacing: true,
+            file_key: None,
+            pending_resume: None,
+            bookmarks: Vec::new(),
+            bookmark_mode: BookmarkMode::None,
+        }
+    }
+
+    /// Associates the reader with a file on disk so progress and bookmarks
+    /// persist across runs. Any saved progress is queued for `run` to offer
+    /// as a resume prompt.
+    fn bind_file(&mut self, path: &str) {
+        let file_key = hash_file_key(path);
+        self.pending_resume = load_progress(&file_key);
+        self.file_key = Some(file_key);
+    }
+
+    /// Applies (or discards) any progress queued by `bind_file`, prompting
+    /// the user when there's a non-trivial position to resume from.
+    fn resolve_pending_resume(&mut self) -> Result<()> {
+        match self.pending_resume.take() {
+            Some(state) => {
+                self.bookmarks = state.bookmarks.clone();
+                if state.word_index > 0 && self.prompt_resume(&state)? {
+                    self.current_word_index = state.word_index.min(self.words.len().saturating_sub(1));
+                    self.wpm = state.wpm.max(50);
+                }
+            }
+            None => self.bookmarks.clear(),
+        }
+        Ok(())
+    }
+
+    fn prompt_resume(&self, state: &ProgressState) -> Result<bool> {
+        let (width, height) = terminal::size()?;
+        let message = format!(
+            "Resume from word {}/{} at {} WPM?  [y] yes  [n] start over",
+            state.word_index + 1,
+            self.words.len(),
+            state.wpm
+        );
+        let row = height / 2;
+        let col = (width / 2).saturating_sub(message.len() as u16 / 2);
+
+        execute!(
+            io::stdout(),
+            Clear(ClearType::All),
+            MoveTo(col, row),
+            SetForegroundColor(self.theme.status),
+            Print(&message),
+        )?;
+        io::stdout().flush()?;
+
+        loop {
+            if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                match code {
+                    KeyCode::Char('y') | KeyCode::Enter => return Ok(true),
+                    KeyCode::Char('n') | KeyCode::Esc => return Ok(false),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn save_state(&self) {
+        if let Some(file_key) = &self.file_key {
+            let state = ProgressState {
+                word_index: self.current_word_index,
+                wpm: self.wpm,
+                bookmarks: self.bookmarks.clone(),
+            };
+            let _ = save_progress(file_key, &state);
+        }
+    }
+
+    fn add_bookmark(&mut self, name: String) {
+        if !name.trim().is_empty() {
+            self.bookmarks.push((name, self.current_word_index));
+        }
+    }
+
+    fn jump_to_bookmark(&mut self, index: usize) {
+        if let Some((_, word_index)) = self.bookmarks.get(index) {
+            self.current_word_index = (*word_index).min(self.words.len().saturating_sub(1));
         }
     }
 
@@ -87,10 +416,63 @@ impl SpeedReader {
         }
     }
 
-    fn get_display_interval(&self) -> Duration {
+    fn base_interval(&self) -> Duration {
         Duration::from_secs_f64(60.0 / self.wpm as f64)
     }
 
+    /// Multiplier applied to the base interval for a single word: longer
+    /// pauses at sentence/clause boundaries, and a small bonus for long
+    /// words, so fluent RSVP reading doesn't race through punctuation.
+    fn pacing_multiplier(&self, word: &str) -> f64 {
+        if !self.dynamic_pacing {
+            return 1.0;
+        }
+
+        let mut multiplier = 1.0;
+        if let Some(last) = word.chars().last() {
+            if matches!(last, '.' | '!' | '?' | '…') {
+                multiplier *= 2.5;
+            } else if matches!(last, ',' | ';' | ':' | '—') {
+                multiplier *= 1.8;
+            }
+        }
+
+        let grapheme_count = word.graphemes(true).count();
+        if grapheme_count > 8 {
+            let length_bonus = ((grapheme_count - 8) as f64 * 0.06).min(0.5);
+            multiplier *= 1.0 + length_bonus;
+        }
+
+        multiplier
+    }
+
+    /// Interval before the word at `index` is replaced, including the extra
+    /// dwell time given to the first word of a heading's section.
+    fn interval_for_word(&self, index: usize) -> Duration {
+        let word = self.words.get(index).map(|s| s.as_str()).unwrap_or("");
+        let mut multiplier = self.pacing_multiplier(word);
+        if self.dynamic_pacing && self.headings.contains_key(&index) {
+            multiplier *= 3.0;
+        }
+        Duration::from_secs_f64(self.base_interval().as_secs_f64() * multiplier)
+    }
+
+    fn get_display_interval(&self) -> Duration {
+        self.interval_for_word(self.current_word_index)
+    }
+
+    /// Sum of the per-word delays for every word not yet shown, used for the
+    /// status line's "Remaining" estimate now that pacing varies per word.
+    fn remaining_duration(&self) -> Duration {
+        (self.current_word_index..self.words.len())
+            .map(|index| self.interval_for_word(index))
+            .sum()
+    }
+
+    fn toggle_dynamic_pacing(&mut self) {
+        self.dynamic_pacing = !self.dynamic_pacing;
+    }
+
     fn start_reading(&mut self) {
         self.is_paused = false;
     }
@@ -100,28 +482,49 @@ impl SpeedReader {
     }
 
     fn render(&self) -> Result<()> {
+        if !matches!(self.bookmark_mode, BookmarkMode::None) {
+            return self.render_bookmark_overlay();
+        }
+
         let (width, height) = terminal::size()?;
         let word = self.current_word().unwrap_or("");
-        let word_len = word.len();
+        let graphemes: Vec<&str> = word.graphemes(true).collect();
 
-        let pivot_index = word_len / 2;
+        let pivot_index = orp_pivot_index(graphemes.len());
+        let left_width: u16 = graphemes[..pivot_index.min(graphemes.len())]
+            .iter()
+            .map(|g| UnicodeWidthStr::width(*g) as u16)
+            .sum();
 
         let row = height / 2;
-        let col = width / 2 - (word_len as u16 / 2);
+        let col = (width / 2).saturating_sub(left_width);
 
         execute!(io::stdout(), Clear(ClearType::All), MoveTo(0, row),)?;
 
-        for (i, c) in word.chars().enumerate() {
+        if let Some(heading) = self.headings.get(&self.current_word_index) {
+            let banner = format!("§ {}", heading);
+            let banner_col = (width / 2).saturating_sub(banner.chars().count() as u16 / 2);
             execute!(
                 io::stdout(),
-                MoveTo(col + i as u16, row),
+                MoveTo(banner_col, row.saturating_sub(2)),
+                SetForegroundColor(self.theme.heading),
+                Print(&banner),
+            )?;
+        }
+
+        let mut cursor = col;
+        for (i, g) in graphemes.iter().enumerate() {
+            execute!(
+                io::stdout(),
+                MoveTo(cursor, row),
                 if i == pivot_index {
-                    SetForegroundColor(Color::Red)
+                    SetForegroundColor(self.theme.pivot)
                 } else {
-                    SetForegroundColor(Color::White)
+                    SetForegroundColor(self.theme.text)
                 },
-                Print(c),
+                Print(*g),
             )?;
+            cursor += UnicodeWidthStr::width(*g).max(1) as u16;
         }
 
         self.render_controls(width, height)?;
@@ -130,6 +533,66 @@ impl SpeedReader {
         Ok(())
     }
 
+    fn render_bookmark_overlay(&self) -> Result<()> {
+        let (_width, height) = terminal::size()?;
+        execute!(io::stdout(), Clear(ClearType::All))?;
+
+        match &self.bookmark_mode {
+            BookmarkMode::Naming(input) => {
+                execute!(
+                    io::stdout(),
+                    MoveTo(2, 1),
+                    SetForegroundColor(self.theme.status),
+                    Print("New bookmark name:"),
+                    MoveTo(2, 3),
+                    SetForegroundColor(self.theme.text),
+                    Print(input),
+                    Print("_"),
+                )?;
+            }
+            BookmarkMode::Overlay { selected } => {
+                execute!(
+                    io::stdout(),
+                    MoveTo(2, 1),
+                    SetForegroundColor(self.theme.status),
+                    Print("Bookmarks   [a] add  [Enter] jump  [Esc] close"),
+                )?;
+
+                if self.bookmarks.is_empty() {
+                    execute!(
+                        io::stdout(),
+                        MoveTo(2, 3),
+                        SetForegroundColor(self.theme.controls_label),
+                        Print("No bookmarks yet"),
+                    )?;
+                } else {
+                    for (i, (name, word_index)) in self.bookmarks.iter().enumerate() {
+                        let row = 3 + i as u16;
+                        if row >= height.saturating_sub(1) {
+                            break;
+                        }
+
+                        execute!(
+                            io::stdout(),
+                            MoveTo(4, row),
+                            SetForegroundColor(if i == *selected {
+                                self.theme.picker_selected
+                            } else {
+                                self.theme.text
+                            }),
+                            Print(if i == *selected { "► " } else { "  " }),
+                            Print(format!("{} (word {})", name, word_index + 1)),
+                        )?;
+                    }
+                }
+            }
+            BookmarkMode::None => {}
+        }
+
+        io::stdout().flush()?;
+        Ok(())
+    }
+
     fn render_controls(&self, width: u16, height: u16) -> Result<()> {
         let controls = [
             ("[Space]", "Play/Pause"),
@@ -138,6 +601,8 @@ impl SpeedReader {
             ("[↓/→]", "Next"),
             ("[r]", "Restart"),
             ("[o]", "Open"),
+            ("[d]", "Pacing"),
+            ("[b]", "Bookmark"),
             ("[q]", "Quit"),
         ];
 
@@ -152,7 +617,7 @@ impl SpeedReader {
         execute!(
             io::stdout(),
             MoveTo(col, row),
-            SetForegroundColor(Color::DarkGrey),
+            SetForegroundColor(self.theme.controls_label),
         )?;
 
         for (i, (key, action)) in controls.iter().enumerate() {
@@ -161,9 +626,9 @@ impl SpeedReader {
             }
             execute!(
                 io::stdout(),
-                SetForegroundColor(Color::Cyan),
+                SetForegroundColor(self.theme.controls_key),
                 Print(key),
-                SetForegroundColor(Color::DarkGrey),
+                SetForegroundColor(self.theme.controls_label),
                 Print(format!(" {} ", action)),
             )?;
         }
@@ -171,22 +636,22 @@ impl SpeedReader {
         let status_row = row - 1;
 
         let status_text = format!(
-            "{} | Word {}/{} | WPM: {} | Percent: {:.0}% | Remaining: {}",
+            "{} | Word {}/{} | WPM: {} | Percent: {:.0}% | Remaining: {}{} | Type: {}",
             if self.is_paused { "PAUSED" } else { "PLAYING" },
             self.current_word_index + 1,
             self.words.len(),
             self.wpm,
             ((self.current_word_index as f64 + 1.0) / self.words.len() as f64) * 100.0,
-            FormatDuration(Duration::from_secs_f64(
-                (self.words.len() - self.current_word_index) as f64 / (self.wpm as f64 / 60.0)
-            ))
+            FormatDuration(self.remaining_duration()),
+            if self.dynamic_pacing { "" } else { " (pacing off)" },
+            self.filetype.label(),
         );
 
         let status_col = width / 2 - (status_text.len() as u16 / 2);
         execute!(
             io::stdout(),
             MoveTo(status_col, status_row),
-            SetForegroundColor(Color::Yellow),
+            SetForegroundColor(self.theme.status),
             Print(status_text),
         )?;
 
@@ -199,10 +664,11 @@ impl SpeedReader {
             return Ok(());
         }
 
+        self.resolve_pending_resume()?;
         self.render()?;
 
         let mut last_update = Instant::now();
-        // Cache this value
+        // Recomputed every word, since dynamic pacing varies the interval per word
         let mut display_interval = self.get_display_interval();
 
         loop {
@@ -210,6 +676,7 @@ impl SpeedReader {
                 let now = Instant::now();
                 if now.duration_since(last_update) >= display_interval {
                     self.render()?;
+                    display_interval = self.get_display_interval();
                     self.next_word();
                     last_update = now;
                 }
@@ -223,57 +690,165 @@ impl SpeedReader {
                 // The event must be shared, or else I miss every other keystroke
                 let ev = event::read()?;
                 if let Event::Key(KeyEvent { code, .. }) = ev {
-                    match code {
-                        KeyCode::Char('q') | KeyCode::Esc => {
-                            return Ok(());
-                        }
-                        KeyCode::Char(' ') => {
-                            if self.is_paused {
-                                self.start_reading();
-                            } else {
-                                self.pause_reading();
+                    let mode = self.bookmark_mode.clone();
+                    match mode {
+                        BookmarkMode::Naming(mut input) => match code {
+                            KeyCode::Enter => {
+                                self.bookmark_mode = BookmarkMode::None;
+                                self.add_bookmark(input);
+                                self.save_state();
+                                self.render()?;
                             }
-                            last_update = Instant::now();
-                            self.render()?;
-                        }
-                        KeyCode::Char('r') => {
-                            self.restart();
-                            self.render()?;
-                        }
-                        KeyCode::Char('l') | KeyCode::Right | KeyCode::Down => {
-                            if self.next_word() {
+                            KeyCode::Esc => {
+                                self.bookmark_mode = BookmarkMode::None;
                                 self.render()?;
-                                last_update = Instant::now();
-                            } else if self.current_word_index >= self.words.len() {
-                                break;
                             }
-                        }
-                        KeyCode::Char('h') | KeyCode::Left | KeyCode::Up => {
-                            if self.previous_word() {
+                            KeyCode::Char(c) if c.is_ascii() && !c.is_ascii_control() => {
+                                input.push(c);
+                                self.bookmark_mode = BookmarkMode::Naming(input);
+                                self.render()?;
+                            }
+                            KeyCode::Backspace => {
+                                input.pop();
+                                self.bookmark_mode = BookmarkMode::Naming(input);
+                                self.render()?;
+                            }
+                            _ => {}
+                        },
+                        BookmarkMode::Overlay { selected } => match code {
+                            KeyCode::Esc | KeyCode::Char('b') => {
+                                self.bookmark_mode = BookmarkMode::None;
+                                self.render()?;
+                            }
+                            KeyCode::Up => {
+                                self.bookmark_mode = BookmarkMode::Overlay {
+                                    selected: selected.saturating_sub(1),
+                                };
+                                self.render()?;
+                            }
+                            KeyCode::Down => {
+                                let max_index = self.bookmarks.len().saturating_sub(1);
+                                self.bookmark_mode = BookmarkMode::Overlay {
+                                    selected: (selected + 1).min(max_index),
+                                };
+                                self.render()?;
+                            }
+                            KeyCode::Enter => {
+                                self.jump_to_bookmark(selected);
+                                self.bookmark_mode = BookmarkMode::None;
+                                display_interval = self.get_display_interval();
+                                self.render()?;
+                            }
+                            KeyCode::Char('a') => {
+                                self.bookmark_mode = BookmarkMode::Naming(String::new());
                                 self.render()?;
+                            }
+                            _ => {}
+                        },
+                        BookmarkMode::None => match code {
+                            KeyCode::Char('q') | KeyCode::Esc => {
+                                self.save_state();
+                                return Ok(());
+                            }
+                            KeyCode::Char(' ') => {
+                                if self.is_paused {
+                                    self.start_reading();
+                                } else {
+                                    self.pause_reading();
+                                    self.save_state();
+                                }
                                 last_update = Instant::now();
+                                self.render()?;
                             }
-                        }
-                        KeyCode::Char('+') | KeyCode::Char('=') => {
-                            self.adjust_wpm(50);
-                            display_interval = self.get_display_interval();
-                            self.render()?;
-                        }
-                        KeyCode::Char('-') => {
-                            self.adjust_wpm(-50);
-                            display_interval = self.get_display_interval();
-                            self.render()?;
-                        }
-                        KeyCode::Char('o') => {
-                            if let Some(new_words) = self.open_file_picker()? {
-                                self.words = new_words;
+                            KeyCode::Char('r') => {
                                 self.restart();
                                 self.render()?;
-                            } else {
+                            }
+                            KeyCode::Char('l') | KeyCode::Right | KeyCode::Down => {
+                                if self.next_word() {
+                                    self.render()?;
+                                    display_interval = self.get_display_interval();
+                                    last_update = Instant::now();
+                                } else if self.current_word_index >= self.words.len() {
+                                    break;
+                                }
+                            }
+                            KeyCode::Char('h') | KeyCode::Left | KeyCode::Up => {
+                                if self.previous_word() {
+                                    self.render()?;
+                                    display_interval = self.get_display_interval();
+                                    last_update = Instant::now();
+                                }
+                            }
+                            KeyCode::Char('+') | KeyCode::Char('=') => {
+                                self.adjust_wpm(50);
+                                display_interval = self.get_display_interval();
+                                self.render()?;
+                            }
+                            KeyCode::Char('-') => {
+                                self.adjust_wpm(-50);
+                                display_interval = self.get_display_interval();
                                 self.render()?;
                             }
+                            KeyCode::Char('o') => {
+                                if let Some((path, document)) = self.open_file_picker()? {
+                                    self.filetype = detect_filetype(Some(&path));
+                                    self.words = document.words;
+                                    self.headings = document.headings;
+                                    self.restart();
+                                    self.bind_file(&path);
+                                    self.resolve_pending_resume()?;
+                                    display_interval = self.get_display_interval();
+                                    self.render()?;
+                                } else {
+                                    self.render()?;
+                                }
+                            }
+                            KeyCode::Char('d') => {
+                                self.toggle_dynamic_pacing();
+                                display_interval = self.get_display_interval();
+                                self.render()?;
+                            }
+                            KeyCode::Char('b') => {
+                                self.bookmark_mode = BookmarkMode::Overlay { selected: 0 };
+                                self.render()?;
+                            }
+                            _ => {}
+                        },
+                    }
+                }
+
+                if let Event::Mouse(MouseEvent { kind, .. }) = ev {
+                    if matches!(self.bookmark_mode, BookmarkMode::None) {
+                        match kind {
+                            MouseEventKind::Down(MouseButton::Left) => {
+                                if self.is_paused {
+                                    self.start_reading();
+                                } else {
+                                    self.pause_reading();
+                                    self.save_state();
+                                }
+                                last_update = Instant::now();
+                                self.render()?;
+                            }
+                            MouseEventKind::ScrollUp => {
+                                if self.previous_word() {
+                                    self.render()?;
+                                    display_interval = self.get_display_interval();
+                                    last_update = Instant::now();
+                                }
+                            }
+                            MouseEventKind::ScrollDown => {
+                                if self.next_word() {
+                                    self.render()?;
+                                    display_interval = self.get_display_interval();
+                                    last_update = Instant::now();
+                                } else if self.current_word_index >= self.words.len() {
+                                    break;
+                                }
+                            }
+                            _ => {}
                         }
-                        _ => {}
                     }
                 }
 
@@ -283,12 +858,13 @@ impl SpeedReader {
             }
         }
 
+        self.save_state();
         Ok(())
     }
 
-    fn open_file_picker(&self) -> Result<Option<Vec<String>>> {
+    fn open_file_picker(&self) -> Result<Option<(String, ParsedDocument)>> {
         let mut filter = String::new();
-        let mut files: Vec<String> = Vec::new();
+        let mut files: Vec<FileMatch> = Vec::new();
         let mut selected_index: usize = 0;
 
         loop {
@@ -296,7 +872,14 @@ impl SpeedReader {
             execute!(io::stdout(), Clear(ClearType::All))?;
 
             if filter.is_empty() {
-                files = self.get_text_files()?;
+                files = self
+                    .get_text_files()?
+                    .into_iter()
+                    .map(|path| FileMatch {
+                        path,
+                        indices: Vec::new(),
+                    })
+                    .collect();
             } else {
                 files = self.filter_text_files(&filter)?;
             }
@@ -305,12 +888,12 @@ impl SpeedReader {
                 execute!(
                     io::stdout(),
                     MoveTo(2, 1),
-                    SetForegroundColor(Color::Yellow),
+                    SetForegroundColor(self.theme.picker_title),
                     Print("No matching text files found"),
                     MoveTo(2, 3),
-                    SetForegroundColor(Color::Cyan),
+                    SetForegroundColor(self.theme.picker_prompt),
                     Print("Type to filter files: "),
-                    SetForegroundColor(Color::White),
+                    SetForegroundColor(self.theme.picker_text),
                     Print(&filter),
                     Print("_"),
                 )?;
@@ -318,12 +901,12 @@ impl SpeedReader {
                 execute!(
                     io::stdout(),
                     MoveTo(2, 1),
-                    SetForegroundColor(Color::Yellow),
+                    SetForegroundColor(self.theme.picker_title),
                     Print("File Picker - Select a text file to open:"),
                     MoveTo(2, 3),
-                    SetForegroundColor(Color::Cyan),
+                    SetForegroundColor(self.theme.picker_prompt),
                     Print("Type to filter files: "),
-                    SetForegroundColor(Color::White),
+                    SetForegroundColor(self.theme.picker_text),
                     Print(&filter),
                     Print("_"),
                 )?;
@@ -332,47 +915,83 @@ impl SpeedReader {
                 let start: usize = selected_index.saturating_sub(max_display / 2);
                 let end = std::cmp::min(start + max_display, files.len());
 
-                for (i, file) in files.iter().enumerate().take(end).skip(start) {
+                for (i, file_match) in files.iter().enumerate().take(end).skip(start) {
                     let row = 5 + (i - start) as u16;
-                    let filename = file.split('/').last().unwrap_or(file);
-
-                    let display_name = if filename.len() > (width - 10) as usize {
-                        format!("...{}", &filename[filename.len() - (width - 13) as usize..])
+                    let path = file_match.path.as_str();
+                    let filename = path.split('/').last().unwrap_or(path);
+                    let filename_chars: Vec<char> = filename.chars().collect();
+
+                    // `FileMatch::indices` are char positions into the full
+                    // path (SkimMatcherV2::fuzzy_indices counts chars, not
+                    // bytes), so everything below tracks chars too, and the
+                    // truncated "...tail" form is rebased onto the real
+                    // filename offsets instead of the literal display string.
+                    let width_budget = (width as usize).saturating_sub(10);
+                    let truncated = filename_chars.len() > width_budget;
+                    let (display_text, tail_start, prefix_len) = if truncated {
+                        let tail_len = (width as usize)
+                            .saturating_sub(13)
+                            .min(filename_chars.len());
+                        let tail_start = filename_chars.len() - tail_len;
+                        let tail: String = filename_chars[tail_start..].iter().collect();
+                        (format!("...{tail}"), tail_start, 3)
                     } else {
-                        filename.to_string()
+                        (filename.to_string(), 0, 0)
                     };
 
                     execute!(
                         io::stdout(),
                         MoveTo(4, row),
                         SetForegroundColor(if i == selected_index {
-                            Color::Red
+                            self.theme.picker_selected
                         } else {
-                            Color::White
+                            self.theme.picker_text
                         }),
                         if i == selected_index {
                             Print("► ")
                         } else {
                             Print("  ")
                         },
-                        Print(display_name),
                     )?;
+
+                    let filename_char_start = path.chars().count() - filename_chars.len();
+                    for (j, c) in display_text.chars().enumerate() {
+                        let matched = j >= prefix_len
+                            && file_match
+                                .indices
+                                .contains(&(filename_char_start + tail_start + (j - prefix_len)));
+                        execute!(
+                            io::stdout(),
+                            SetForegroundColor(if matched {
+                                self.theme.picker_match
+                            } else {
+                                self.theme.picker_text
+                            }),
+                            Print(c),
+                        )?;
+                    }
                 }
             }
 
             io::stdout().flush()?;
 
-            if let Event::Key(KeyEvent {
-                code, modifiers, ..
-            }) = event::read()?
-            {
-                match code {
+            let max_display = (height - 6) as usize;
+            let start: usize = selected_index.saturating_sub(max_display / 2);
+
+            match event::read()? {
+                Event::Key(KeyEvent {
+                    code, modifiers, ..
+                }) => match code {
                     KeyCode::Esc => return Ok(None),
                     KeyCode::Enter => {
-                        if let Some(file) = files.get(selected_index) {
-                            let text = read_file(file)?;
+                        if let Some(file_match) = files.get(selected_index) {
+                            let text = read_file(&file_match.path)?;
                             if !text.trim().is_empty() {
-                                return Ok(Some(parse_words(&text)));
+                                let filetype = detect_filetype(Some(&file_match.path));
+                                return Ok(Some((
+                                    file_match.path.clone(),
+                                    parse_document(&text, filetype),
+                                )));
                             }
                         }
                     }
@@ -405,12 +1024,35 @@ impl SpeedReader {
                         selected_index = 0;
                     }
                     _ => {}
-                }
+                },
+                Event::Mouse(MouseEvent { kind, row, .. }) => match kind {
+                    MouseEventKind::ScrollUp => {
+                        if selected_index > 0 {
+                            selected_index -= 1;
+                        }
+                    }
+                    MouseEventKind::ScrollDown => {
+                        if selected_index < files.len().saturating_sub(1) {
+                            selected_index += 1;
+                        }
+                    }
+                    MouseEventKind::Down(MouseButton::Left) if row >= 5 => {
+                        let clicked_index = start + (row - 5) as usize;
+                        if let Some(file_match) = files.get(clicked_index) {
+                            let text = read_file(&file_match.path)?;
+                            if !text.trim().is_empty() {
+                                let filetype = detect_filetype(Some(&file_match.path));
+                                return Ok(Some((
+                                    file_match.path.clone(),
+                                    parse_document(&text, filetype),
+                                )));
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
             }
-
-            // if event::poll(Duration::from_millis(10))? {
-            //     if let Event::Resize(_, _) = event::read()? {}
-            // }
         }
     }
 
@@ -429,7 +1071,28 @@ impl SpeedReader {
                 if path.is_file() {
                     if let Some(ext) = path.extension() {
                         let ext_lower = ext.to_string_lossy().to_lowercase();
-                        if matches!(ext_lower.as_str(), "txt" | "md" | "rst" | "log" | "text") {
+                        if matches!(
+                            ext_lower.as_str(),
+                            "txt" | "md"
+                                | "markdown"
+                                | "rst"
+                                | "log"
+                                | "text"
+                                | "rs"
+                                | "py"
+                                | "js"
+                                | "ts"
+                                | "tsx"
+                                | "jsx"
+                                | "go"
+                                | "c"
+                                | "h"
+                                | "cpp"
+                                | "hpp"
+                                | "java"
+                                | "rb"
+                                | "sh"
+                        ) {
                             files.push(path.to_string_lossy().to_string());
                         }
                     }
@@ -441,16 +1104,21 @@ impl SpeedReader {
         Ok(files)
     }
 
-    fn filter_text_files(&self, filter: &str) -> Result<Vec<String>> {
+    fn filter_text_files(&self, filter: &str) -> Result<Vec<FileMatch>> {
         let all_files = self.get_text_files()?;
-        let filter_lower = filter.to_lowercase();
+        let matcher = SkimMatcherV2::default();
 
-        let filtered: Vec<String> = all_files
+        let mut scored: Vec<(i64, FileMatch)> = all_files
             .into_iter()
-            .filter(|path| path.to_lowercase().contains(&filter_lower))
+            .filter_map(|path| {
+                let (score, indices) = matcher.fuzzy_indices(&path, filter)?;
+                Some((score, FileMatch { path, indices }))
+            })
             .collect();
 
-        Ok(filtered)
+        scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        Ok(scored.into_iter().map(|(_, file_match)| file_match).collect())
     }
 }
 
@@ -471,32 +1139,192 @@ fn parse_words(text: &str) -> Vec<String> {
         .collect()
 }
 
+/// The kind of content being read, keyed off the file extension already
+/// gathered by `get_text_files`, so each format can be parsed appropriately.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FileType {
+    PlainText,
+    Markdown,
+    Code,
+}
+
+impl FileType {
+    fn label(&self) -> &'static str {
+        match self {
+            FileType::PlainText => "text",
+            FileType::Markdown => "markdown",
+            FileType::Code => "code",
+        }
+    }
+}
+
+fn detect_filetype(path: Option<&str>) -> FileType {
+    let Some(path) = path else {
+        return FileType::PlainText;
+    };
+
+    let ext = std::path::Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+
+    match ext.as_str() {
+        "md" | "markdown" => FileType::Markdown,
+        "rs" | "py" | "js" | "ts" | "tsx" | "jsx" | "go" | "c" | "h" | "cpp" | "hpp" | "java"
+        | "rb" | "sh" => FileType::Code,
+        _ => FileType::PlainText,
+    }
+}
+
+/// Words to read plus the section breaks found while parsing, keyed by the
+/// index of the word each heading starts on.
+struct ParsedDocument {
+    words: Vec<String>,
+    headings: HashMap<usize, String>,
+}
+
+fn parse_document(text: &str, filetype: FileType) -> ParsedDocument {
+    match filetype {
+        FileType::Markdown => parse_markdown(text),
+        FileType::Code => ParsedDocument {
+            words: parse_code_words(text),
+            headings: HashMap::new(),
+        },
+        FileType::PlainText => ParsedDocument {
+            words: parse_words(text),
+            headings: HashMap::new(),
+        },
+    }
+}
+
+/// Strips the wrapping punctuation (brackets, quotes, statement
+/// terminators) off each token so identifiers, keywords, comment text, and
+/// string contents read naturally; bare operator/punctuation tokens (`=>`,
+/// `::`, `{`) have no alphanumeric content left and are dropped entirely by
+/// the same filter `parse_words` uses.
+fn parse_code_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|token| {
+            token.trim_matches(|c: char| {
+                matches!(
+                    c,
+                    '"' | '\'' | '(' | ')' | '{' | '}' | '[' | ']' | ';' | ',' | '`'
+                )
+            })
+        })
+        .filter(|s| s.contains(char::is_alphanumeric))
+        .map(|s| s.to_string())
+        .collect()
+}
+
+/// Strips Markdown structure so headings, emphasis, and link syntax aren't
+/// read aloud as literal words; each heading is recorded as a section break.
+fn parse_markdown(text: &str) -> ParsedDocument {
+    let mut words = Vec::new();
+    let mut headings = HashMap::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        let heading_level = trimmed.chars().take_while(|&c| c == '#').count();
+        let is_heading =
+            (1..=6).contains(&heading_level) && trimmed.as_bytes().get(heading_level) == Some(&b' ');
+
+        let content = if is_heading {
+            trimmed[heading_level..].trim_start()
+        } else {
+            trimmed
+        };
+
+        let cleaned = strip_markdown_inline(content);
+        let line_words: Vec<String> = cleaned
+            .split_whitespace()
+            .filter(|s| s.contains(char::is_alphanumeric))
+            .map(|s| s.to_string())
+            .collect();
+
+        if is_heading && !line_words.is_empty() {
+            headings.insert(words.len(), cleaned.trim().to_string());
+        }
+
+        words.extend(line_words);
+    }
+
+    ParsedDocument { words, headings }
+}
+
+/// Removes `*`/`_`/backtick emphasis markers and collapses `[text](url)`
+/// links down to their visible text.
+fn strip_markdown_inline(line: &str) -> String {
+    let chars: Vec<char> = line.chars().collect();
+    let mut result = String::with_capacity(line.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '*' | '_' | '`' => i += 1,
+            '[' => {
+                if let Some(rel_close) = chars[i..].iter().position(|&c| c == ']') {
+                    let text_start = i + 1;
+                    let text_end = i + rel_close;
+                    let after_bracket = text_end + 1;
+                    if chars.get(after_bracket) == Some(&'(') {
+                        if let Some(rel_paren) =
+                            chars[after_bracket..].iter().position(|&c| c == ')')
+                        {
+                            result.extend(&chars[text_start..text_end]);
+                            i = after_bracket + rel_paren + 1;
+                            continue;
+                        }
+                    }
+                }
+                result.push(chars[i]);
+                i += 1;
+            }
+            c => {
+                result.push(c);
+                i += 1;
+            }
+        }
+    }
+
+    result
+}
+
 fn main() -> Result<()> {
     let args = Args::parse();
 
+    let mut opened_file: Option<String> = None;
     let text = if let Some(text) = args.text {
         text
     } else if let Some(file) = args.file {
-        read_file(&file)?
+        let content = read_file(&file)?;
+        opened_file = Some(file);
+        content
     } else {
         read_stdin()?
     };
 
-    let words = parse_words(&text);
+    let filetype = detect_filetype(opened_file.as_deref());
+    let document = parse_document(&text, filetype);
 
-    if words.is_empty() {
+    if document.words.is_empty() {
         eprintln!("No words found in input");
         process::exit(1);
     }
 
     terminal::enable_raw_mode()?;
-    execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+    let theme = detect_theme(args.theme);
+    execute!(io::stdout(), EnterAlternateScreen, Hide, EnableMouseCapture)?;
 
-    let mut reader = SpeedReader::new(words, args.wpm);
+    let mut reader = SpeedReader::new(document, args.wpm, filetype, theme);
+    if let Some(file) = &opened_file {
+        reader.bind_file(file);
+    }
 
     let result = reader.run();
 
-    execute!(io::stdout(), LeaveAlternateScreen, Show)?;
+    execute!(io::stdout(), DisableMouseCapture, LeaveAlternateScreen, Show)?;
     terminal::disable_raw_mode()?;
 
     result?;